@@ -0,0 +1,218 @@
+use std::num::NonZeroU8;
+
+use crate::{exchanges::binance::pairs::BinanceTradingType, CexExchange};
+
+/// assigns a stable, nonzero byte code to a crate type so it can be packed
+/// into a compact binary record instead of repeating full JSON strings.
+/// `0` is reserved for "no code / unset" and must never be returned.
+pub trait ByteCode: Sized {
+    fn byte_code(&self) -> NonZeroU8;
+}
+
+impl ByteCode for CexExchange {
+    fn byte_code(&self) -> NonZeroU8 {
+        match self {
+            CexExchange::Binance => NonZeroU8::new(1).unwrap(),
+            CexExchange::Coinbase => NonZeroU8::new(2).unwrap(),
+            CexExchange::Okex => NonZeroU8::new(3).unwrap(),
+            CexExchange::Kucoin => NonZeroU8::new(4).unwrap()
+        }
+    }
+}
+
+impl TryFrom<u8> for CexExchange {
+    type Error = eyre::Report;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(CexExchange::Binance),
+            2 => Ok(CexExchange::Coinbase),
+            3 => Ok(CexExchange::Okex),
+            4 => Ok(CexExchange::Kucoin),
+            other => Err(eyre::ErrReport::msg(format!("UNKNOWN CexExchange byte code '{other}'")))
+        }
+    }
+}
+
+impl ByteCode for BinanceTradingType {
+    fn byte_code(&self) -> NonZeroU8 {
+        match self {
+            BinanceTradingType::Spot => NonZeroU8::new(1).unwrap(),
+            BinanceTradingType::Perpetual => NonZeroU8::new(2).unwrap(),
+            BinanceTradingType::Margin => NonZeroU8::new(3).unwrap(),
+            BinanceTradingType::Futures => NonZeroU8::new(4).unwrap(),
+            BinanceTradingType::Option => NonZeroU8::new(5).unwrap(),
+            BinanceTradingType::Other => NonZeroU8::new(6).unwrap()
+        }
+    }
+}
+
+impl TryFrom<u8> for BinanceTradingType {
+    type Error = eyre::Report;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(BinanceTradingType::Spot),
+            2 => Ok(BinanceTradingType::Perpetual),
+            3 => Ok(BinanceTradingType::Margin),
+            4 => Ok(BinanceTradingType::Futures),
+            5 => Ok(BinanceTradingType::Option),
+            6 => Ok(BinanceTradingType::Other),
+            other => Err(eyre::ErrReport::msg(format!("UNKNOWN BinanceTradingType byte code '{other}'")))
+        }
+    }
+}
+
+/// byte-code (de)serialization for [`CexExchange`], for use via `#[serde(with = "crate::codec::cex_exchange_byte_code")]`
+pub mod cex_exchange_byte_code {
+    use std::num::NonZeroU8;
+
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    use super::ByteCode;
+    use crate::CexExchange;
+
+    pub fn serialize<S>(value: &CexExchange, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer
+    {
+        serializer.serialize_u8(value.byte_code().get())
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<CexExchange, D::Error>
+    where
+        D: Deserializer<'de>
+    {
+        let code = u8::deserialize(deserializer)?;
+        NonZeroU8::new(code).ok_or_else(|| de::Error::custom("byte code '0' is reserved for 'no code / unset'"))?;
+
+        CexExchange::try_from(code).map_err(de::Error::custom)
+    }
+}
+
+/// byte-code (de)serialization for [`BinanceTradingType`], for use via `#[serde(with = "crate::codec::binance_trading_type_byte_code")]`
+pub mod binance_trading_type_byte_code {
+    use std::num::NonZeroU8;
+
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    use super::ByteCode;
+    use crate::exchanges::binance::pairs::BinanceTradingType;
+
+    pub fn serialize<S>(value: &BinanceTradingType, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer
+    {
+        serializer.serialize_u8(value.byte_code().get())
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<BinanceTradingType, D::Error>
+    where
+        D: Deserializer<'de>
+    {
+        let code = u8::deserialize(deserializer)?;
+        NonZeroU8::new(code).ok_or_else(|| de::Error::custom("byte code '0' is reserved for 'no code / unset'"))?;
+
+        BinanceTradingType::try_from(code).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+
+    const CEX_EXCHANGE_VARIANTS: &[CexExchange] = &[CexExchange::Binance, CexExchange::Coinbase, CexExchange::Okex, CexExchange::Kucoin];
+
+    const BINANCE_TRADING_TYPE_VARIANTS: &[BinanceTradingType] = &[
+        BinanceTradingType::Spot,
+        BinanceTradingType::Perpetual,
+        BinanceTradingType::Margin,
+        BinanceTradingType::Futures,
+        BinanceTradingType::Option,
+        BinanceTradingType::Other
+    ];
+
+    #[derive(Serialize, Deserialize)]
+    struct CexExchangeWrapper(#[serde(with = "cex_exchange_byte_code")] CexExchange);
+
+    #[derive(Serialize, Deserialize)]
+    struct BinanceTradingTypeWrapper(#[serde(with = "binance_trading_type_byte_code")] BinanceTradingType);
+
+    #[test]
+    fn cex_exchange_byte_code_round_trips_every_variant() {
+        for exchange in CEX_EXCHANGE_VARIANTS {
+            let code = exchange.byte_code();
+            assert_ne!(code.get(), 0);
+            assert_eq!(CexExchange::try_from(code.get()).unwrap(), *exchange);
+        }
+    }
+
+    #[test]
+    fn binance_trading_type_byte_code_round_trips_every_variant() {
+        for trading_type in BINANCE_TRADING_TYPE_VARIANTS {
+            let code = trading_type.byte_code();
+            assert_ne!(code.get(), 0);
+            assert_eq!(BinanceTradingType::try_from(code.get()).unwrap(), *trading_type);
+        }
+    }
+
+    #[test]
+    fn cex_exchange_try_from_rejects_zero() {
+        assert!(CexExchange::try_from(0u8).is_err());
+    }
+
+    #[test]
+    fn cex_exchange_try_from_rejects_out_of_range_code() {
+        assert!(CexExchange::try_from(5u8).is_err());
+    }
+
+    #[test]
+    fn binance_trading_type_try_from_rejects_zero() {
+        assert!(BinanceTradingType::try_from(0u8).is_err());
+    }
+
+    #[test]
+    fn binance_trading_type_try_from_rejects_out_of_range_code() {
+        assert!(BinanceTradingType::try_from(7u8).is_err());
+    }
+
+    #[test]
+    fn cex_exchange_byte_code_serde_round_trips() {
+        for exchange in CEX_EXCHANGE_VARIANTS {
+            let json = serde_json::to_string(&CexExchangeWrapper(*exchange)).unwrap();
+            let decoded: CexExchangeWrapper = serde_json::from_str(&json).unwrap();
+            assert_eq!(decoded.0, *exchange);
+        }
+    }
+
+    #[test]
+    fn binance_trading_type_byte_code_serde_round_trips() {
+        for trading_type in BINANCE_TRADING_TYPE_VARIANTS {
+            let json = serde_json::to_string(&BinanceTradingTypeWrapper(*trading_type)).unwrap();
+            let decoded: BinanceTradingTypeWrapper = serde_json::from_str(&json).unwrap();
+            assert_eq!(decoded.0, *trading_type);
+        }
+    }
+
+    #[test]
+    fn cex_exchange_byte_code_deserialize_rejects_zero() {
+        assert!(serde_json::from_str::<CexExchangeWrapper>("0").is_err());
+    }
+
+    #[test]
+    fn cex_exchange_byte_code_deserialize_rejects_out_of_range_code() {
+        assert!(serde_json::from_str::<CexExchangeWrapper>("5").is_err());
+    }
+
+    #[test]
+    fn binance_trading_type_byte_code_deserialize_rejects_zero() {
+        assert!(serde_json::from_str::<BinanceTradingTypeWrapper>("0").is_err());
+    }
+
+    #[test]
+    fn binance_trading_type_byte_code_deserialize_rejects_out_of_range_code() {
+        assert!(serde_json::from_str::<BinanceTradingTypeWrapper>("7").is_err());
+    }
+}
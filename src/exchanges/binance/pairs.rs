@@ -4,6 +4,10 @@ use serde::{Deserialize, Serialize};
 
 use crate::{exchanges::normalized::types::NormalizedTradingPair, normalized::types::NormalizedTradingType, CexExchange};
 
+/// known Binance quote assets, ordered longest-first so that a concatenated
+/// symbol like `BTCTUSD` is split as `BTC`/`TUSD` rather than `BTCT`/`USD`
+const QUOTE_ASSETS: &[&str] = &["FDUSD", "BUSD", "USDT", "USDC", "TUSD", "DAI", "TRY", "EUR", "GBP", "AUD", "BRL", "USD", "BTC", "ETH", "BNB"];
+
 #[derive(Debug, Clone, Eq, PartialEq, Hash, PartialOrd)]
 pub struct BinanceTradingPair(pub(crate) String);
 
@@ -16,7 +20,32 @@ impl BinanceTradingPair {
         !s.contains('-') && !s.contains('_') && !s.contains('/')
     }
 
+    /// splits this pair's symbol into `(base, quote)` by matching the longest
+    /// known quote asset suffix, e.g. `BTCUSDT` -> `(BTC, USDT)`
+    ///
+    /// relies on `QUOTE_ASSETS` already being ordered longest-first, see the
+    /// `quote_assets_are_ordered_longest_first` test
+    pub fn split_base_quote(&self) -> Option<(&str, &str)> {
+        QUOTE_ASSETS.iter().find_map(|&quote| {
+            let prefix_len = self.0.len().checked_sub(quote.len())?;
+            if prefix_len == 0 || !self.0.is_char_boundary(prefix_len) {
+                return None
+            }
+
+            let (prefix, suffix) = self.0.split_at(prefix_len);
+            (suffix == quote).then_some((prefix, quote))
+        })
+    }
+
     pub fn normalize(&self) -> NormalizedTradingPair {
+        if let Some((base, quote)) = self.split_base_quote() {
+            return NormalizedTradingPair::new_base_quote(CexExchange::Binance, base, quote, None, None)
+        }
+
+        self.normalize_no_split()
+    }
+
+    pub fn normalize_no_split(&self) -> NormalizedTradingPair {
         NormalizedTradingPair::new_no_base_quote(CexExchange::Binance, &self.0)
     }
 
@@ -45,9 +74,39 @@ impl<'de> Deserialize<'de> for BinanceTradingPair {
     where
         D: serde::Deserializer<'de>
     {
-        let s = String::deserialize(deserializer)?;
+        deserializer.deserialize_str(BinanceTradingPairVisitor)
+    }
+}
+
+struct BinanceTradingPairVisitor;
+
+impl<'de> serde::de::Visitor<'de> for BinanceTradingPairVisitor {
+    type Value = BinanceTradingPair;
 
-        Ok(BinanceTradingPair(s))
+    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str("a Binance trading pair symbol containing no '-', '_', or '/'")
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error
+    {
+        BinanceTradingPair::try_from(value).map_err(serde::de::Error::custom)
+    }
+
+    fn visit_borrowed_str<E>(self, value: &'de str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error
+    {
+        BinanceTradingPair::try_from(value).map_err(serde::de::Error::custom)
+    }
+
+    fn visit_bytes<E>(self, value: &[u8]) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error
+    {
+        let s = std::str::from_utf8(value).map_err(serde::de::Error::custom)?;
+        BinanceTradingPair::try_from(s).map_err(serde::de::Error::custom)
     }
 }
 
@@ -141,3 +200,71 @@ impl FromStr for BinanceTradingType {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quote_assets_are_ordered_longest_first() {
+        assert!(QUOTE_ASSETS.windows(2).all(|w| w[0].len() >= w[1].len()), "QUOTE_ASSETS must be ordered longest-first: {QUOTE_ASSETS:?}");
+    }
+
+    #[test]
+    fn split_base_quote_prefers_longest_quote_match() {
+        let pair = BinanceTradingPair("ETHUSDT".to_string());
+        assert_eq!(pair.split_base_quote(), Some(("ETH", "USDT")));
+
+        let pair = BinanceTradingPair("BTCTUSD".to_string());
+        assert_eq!(pair.split_base_quote(), Some(("BTC", "TUSD")));
+    }
+
+    #[test]
+    fn split_base_quote_does_not_let_a_shorter_quote_steal_the_match() {
+        let pair = BinanceTradingPair("BTCUSDC".to_string());
+        assert_eq!(pair.split_base_quote(), Some(("BTC", "USDC")));
+
+        let pair = BinanceTradingPair("BTCBUSD".to_string());
+        assert_eq!(pair.split_base_quote(), Some(("BTC", "BUSD")));
+    }
+
+    #[test]
+    fn split_base_quote_returns_none_when_no_quote_matches() {
+        let pair = BinanceTradingPair("ZZZZZ".to_string());
+        assert_eq!(pair.split_base_quote(), None);
+    }
+
+    #[test]
+    fn split_base_quote_rejects_an_empty_base() {
+        let pair = BinanceTradingPair("USDT".to_string());
+        assert_eq!(pair.split_base_quote(), None);
+    }
+
+    #[test]
+    fn split_base_quote_does_not_panic_on_a_non_char_boundary_cut() {
+        let pair = BinanceTradingPair("A😀".to_uppercase());
+        assert_eq!(pair.split_base_quote(), None);
+    }
+
+    #[test]
+    fn deserialize_rejects_dash_delimited_pairs() {
+        let err = serde_json::from_str::<BinanceTradingPair>("\"BTC-USDT\"").unwrap_err();
+        assert!(err.to_string().contains("INVALID"));
+    }
+
+    #[test]
+    fn deserialize_rejects_underscore_delimited_pairs() {
+        assert!(serde_json::from_str::<BinanceTradingPair>("\"BTC_USDT\"").is_err());
+    }
+
+    #[test]
+    fn deserialize_rejects_slash_delimited_pairs() {
+        assert!(serde_json::from_str::<BinanceTradingPair>("\"BTC/USDT\"").is_err());
+    }
+
+    #[test]
+    fn deserialize_uppercases_lowercase_input() {
+        let pair = serde_json::from_str::<BinanceTradingPair>("\"btcusdt\"").unwrap();
+        assert_eq!(pair, BinanceTradingPair("BTCUSDT".to_string()));
+    }
+}
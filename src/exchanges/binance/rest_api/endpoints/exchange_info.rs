@@ -0,0 +1,184 @@
+use std::collections::HashMap;
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::{exchanges::binance::pairs::BinanceTradingPair, exchanges::normalized::types::NormalizedTradingPair, CexExchange};
+
+/// mirrors Binance's `/api/v3/exchangeInfo` response
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct BinanceExchangeInfo {
+    pub symbols: Vec<BinanceInstrument>,
+    /// symbol -> index into `symbols`, built once in [`BinanceExchangeInfo::new`]
+    /// so repeated [`normalize_pair`](BinanceExchangeInfo::normalize_pair) calls are O(1)
+    #[serde(skip)]
+    index: HashMap<String, usize>
+}
+
+impl BinanceExchangeInfo {
+    pub fn new(symbols: Vec<BinanceInstrument>) -> Self {
+        let index = symbols.iter().enumerate().map(|(i, instrument)| (instrument.symbol.clone(), i)).collect();
+        Self { symbols, index }
+    }
+
+    /// looks up `pair` and, if found, normalizes it using the authoritative
+    /// base/quote split from this exchange info rather than the suffix heuristic
+    /// in [`BinanceTradingPair::normalize`](crate::exchanges::binance::pairs::BinanceTradingPair::normalize)
+    pub fn normalize_pair(&self, pair: &BinanceTradingPair) -> Option<NormalizedTradingPair> {
+        let idx = *self.index.get(pair.0.as_str())?;
+        Some(self.symbols[idx].normalize())
+    }
+}
+
+impl<'de> Deserialize<'de> for BinanceExchangeInfo {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>
+    {
+        #[derive(Deserialize)]
+        struct Raw {
+            symbols: Vec<BinanceInstrument>
+        }
+
+        let raw = Raw::deserialize(deserializer)?;
+        Ok(BinanceExchangeInfo::new(raw.symbols))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct BinanceInstrument {
+    pub symbol: String,
+    pub status: String,
+    pub base_asset: String,
+    pub base_asset_precision: u32,
+    pub quote_asset: String,
+    pub quote_precision: u32,
+    pub order_types: Vec<String>,
+    pub is_spot_trading_allowed: bool,
+    pub is_margin_trading_allowed: bool,
+    pub filters: Vec<BinanceSymbolFilter>
+}
+
+impl BinanceInstrument {
+    pub fn normalize(&self) -> NormalizedTradingPair {
+        NormalizedTradingPair::new_base_quote(
+            CexExchange::Binance,
+            &self.base_asset,
+            &self.quote_asset,
+            Some(self.base_asset_precision),
+            Some(self.quote_precision)
+        )
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "filterType")]
+pub enum BinanceSymbolFilter {
+    #[serde(rename = "PRICE_FILTER")]
+    PriceFilter {
+        #[serde(rename = "minPrice")]
+        min_price: Decimal,
+        #[serde(rename = "maxPrice")]
+        max_price: Decimal,
+        #[serde(rename = "tickSize")]
+        tick_size: Decimal
+    },
+    #[serde(rename = "LOT_SIZE")]
+    LotSize {
+        #[serde(rename = "minQty")]
+        min_qty:   Decimal,
+        #[serde(rename = "maxQty")]
+        max_qty:   Decimal,
+        #[serde(rename = "stepSize")]
+        step_size: Decimal
+    },
+    #[serde(rename = "MIN_NOTIONAL")]
+    MinNotional {
+        #[serde(rename = "minNotional")]
+        min_notional: Decimal
+    },
+    #[serde(other)]
+    Other
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+
+    use super::*;
+
+    const SAMPLE_EXCHANGE_INFO: &str = r#"{
+        "symbols": [
+            {
+                "symbol": "BTCUSDT",
+                "status": "TRADING",
+                "baseAsset": "BTC",
+                "baseAssetPrecision": 8,
+                "quoteAsset": "USDT",
+                "quotePrecision": 6,
+                "orderTypes": ["LIMIT", "MARKET"],
+                "isSpotTradingAllowed": true,
+                "isMarginTradingAllowed": true,
+                "filters": [
+                    {"filterType": "PRICE_FILTER", "minPrice": "0.01", "maxPrice": "1000000.00", "tickSize": "0.01"},
+                    {"filterType": "LOT_SIZE", "minQty": "0.00001", "maxQty": "9000.00", "stepSize": "0.00001"},
+                    {"filterType": "MIN_NOTIONAL", "minNotional": "10.00"}
+                ]
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn deserializes_a_realistic_exchange_info_response() {
+        let info: BinanceExchangeInfo = serde_json::from_str(SAMPLE_EXCHANGE_INFO).unwrap();
+
+        assert_eq!(info.symbols.len(), 1);
+
+        let instrument = &info.symbols[0];
+        assert_eq!(instrument.symbol, "BTCUSDT");
+        assert_eq!(instrument.base_asset, "BTC");
+        assert_eq!(instrument.base_asset_precision, 8);
+        assert_eq!(instrument.quote_asset, "USDT");
+        assert_eq!(instrument.quote_precision, 6);
+        assert_eq!(
+            instrument.filters,
+            vec![
+                BinanceSymbolFilter::PriceFilter {
+                    min_price: Decimal::new(1, 2),
+                    max_price: Decimal::new(100000000, 2),
+                    tick_size: Decimal::new(1, 2)
+                },
+                BinanceSymbolFilter::LotSize { min_qty: Decimal::new(1, 5), max_qty: Decimal::new(900000, 2), step_size: Decimal::new(1, 5) },
+                BinanceSymbolFilter::MinNotional { min_notional: Decimal::new(1000, 2) }
+            ]
+        );
+    }
+
+    #[test]
+    fn normalize_pair_is_indexed_after_deserializing() {
+        let info: BinanceExchangeInfo = serde_json::from_str(SAMPLE_EXCHANGE_INFO).unwrap();
+        let pair = BinanceTradingPair("BTCUSDT".to_string());
+
+        assert!(info.normalize_pair(&pair).is_some());
+    }
+
+    #[test]
+    fn instrument_normalize_carries_precision_through() {
+        let instrument = BinanceInstrument {
+            symbol: "BTCUSDT".to_string(),
+            status: "TRADING".to_string(),
+            base_asset: "BTC".to_string(),
+            base_asset_precision: 8,
+            quote_asset: "USDT".to_string(),
+            quote_precision: 6,
+            order_types: vec![],
+            is_spot_trading_allowed: true,
+            is_margin_trading_allowed: true,
+            filters: vec![]
+        };
+
+        let normalized = NormalizedTradingPair::new_base_quote(CexExchange::Binance, "BTC", "USDT", Some(8), Some(6));
+        assert_eq!(instrument.normalize(), normalized);
+    }
+}